@@ -6,7 +6,8 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 //! A `mod` containing constructs useful in infix operator parsing with the precedence climbing
-//! method.
+//! method, as well as [`PrattParser`](struct.PrattParser.html), which extends the same idea to
+//! prefix and postfix operators.
 
 use std::collections::HashMap;
 use std::iter::Peekable;
@@ -22,7 +23,11 @@ pub enum Assoc {
     /// Left `Operator` associativity
     Left,
     /// Right `Operator` associativity
-    Right
+    Right,
+    /// Non-associative `Operator`. Chaining two `Operator`s that share a precedence level and are
+    /// both `Non`-associative (e.g. `a < b < c`) is rejected instead of being silently
+    /// re-associated.
+    Non
 }
 
 /// A `struct` defining an infix operator used in [`PrecClimber`](struct.PrecClimber.html).
@@ -68,6 +73,74 @@ impl<R: RuleType> BitOr for Operator<R> {
     }
 }
 
+/// An `enum` describing an operator's placement relative to its operand(s), for use with
+/// [`PrattOp`](struct.PrattOp.html).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Fixity {
+    /// A prefix `Operator`, written before its single operand, e.g. unary `-`.
+    Prefix,
+    /// A postfix `Operator`, written after its single operand, e.g. `?` or postfix `!`.
+    Postfix,
+    /// An infix `Operator`, written between its two operands, with the given associativity.
+    Infix(Assoc)
+}
+
+/// A `struct` defining a prefix, postfix, or infix operator used in
+/// [`PrattParser`](struct.PrattParser.html).
+#[derive(Debug)]
+pub struct PrattOp<R: RuleType> {
+    rule: R,
+    fixity: Fixity,
+    next: Option<Box<PrattOp<R>>>
+}
+
+impl<R: RuleType> PrattOp<R> {
+    /// Creates a new prefix `PrattOp` from a `Rule`.
+    pub fn prefix(rule: R) -> PrattOp<R> {
+        PrattOp {
+            rule,
+            fixity: Fixity::Prefix,
+            next: None
+        }
+    }
+
+    /// Creates a new postfix `PrattOp` from a `Rule`.
+    pub fn postfix(rule: R) -> PrattOp<R> {
+        PrattOp {
+            rule,
+            fixity: Fixity::Postfix,
+            next: None
+        }
+    }
+
+    /// Creates a new infix `PrattOp` from a `Rule` and `Assoc`.
+    pub fn infix(rule: R, assoc: Assoc) -> PrattOp<R> {
+        PrattOp {
+            rule,
+            fixity: Fixity::Infix(assoc),
+            next: None
+        }
+    }
+}
+
+impl<R: RuleType> BitOr for PrattOp<R> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self {
+        self.next = Some(Box::new(rhs));
+        self
+    }
+}
+
+/// The operator table backing a [`PrecClimber`](struct.PrecClimber.html), either a `HashMap` built
+/// by [`PrecClimber::new`](struct.PrecClimber.html#method.new) or a sorted, hash-free lookup slice
+/// built by [`PrecClimber::new_dense`](struct.PrecClimber.html#method.new_dense).
+#[derive(Debug)]
+enum Ops<R> {
+    Hashed(HashMap<R, (u32, Assoc)>),
+    Dense(Box<[(R, u32, Assoc)]>)
+}
+
 /// A `struct` useful in order to perform [precedence climbing][1] on infix expressions contained in
 /// a [`Pairs`](../iterators/struct.Pairs.html). The token pairs contained in the `Pairs` should
 /// start with a *primary* pair and then alternate between an *operator* and a *primary*.
@@ -75,7 +148,7 @@ impl<R: RuleType> BitOr for Operator<R> {
 /// [1]: https://en.wikipedia.org/wiki/Operator-precedence_parser#Precedence_climbing_method
 #[derive(Debug)]
 pub struct PrecClimber<R: RuleType> {
-    ops: HashMap<R, (u32, Assoc)>
+    ops: Ops<R>
 }
 
 impl<R: RuleType> PrecClimber<R> {
@@ -120,7 +193,65 @@ impl<R: RuleType> PrecClimber<R> {
         });
 
         PrecClimber {
-            ops
+            ops: Ops::Hashed(ops)
+        }
+    }
+
+    /// Creates a new `PrecClimber` from the `Operator`s contained in `ops`, the same way
+    /// [`new`](struct.PrecClimber.html#method.new) does, but lowers the operator table into a
+    /// sorted slice searched with `binary_search_by_key` instead of a `HashMap`. This avoids
+    /// per-token hashing during [`climb`](struct.PrecClimber.html#method.climb), which matters for
+    /// workloads that parse many expressions against the same operator table, and lets the
+    /// resulting `PrecClimber` be built once (e.g. behind a `lazy_static!`) and shared cheaply
+    /// across parses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pest::prec_climber::{Assoc, Operator, PrecClimber};
+    /// # #[allow(non_camel_case_types)]
+    /// # #[allow(dead_code)]
+    /// # #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    /// # enum Rule {
+    /// #     plus,
+    /// #     minus
+    /// # }
+    /// let climber = PrecClimber::new_dense(vec![
+    ///     Operator::new(Rule::plus, Assoc::Left) | Operator::new(Rule::minus, Assoc::Left)
+    /// ]);
+    /// ```
+    pub fn new_dense(ops: Vec<Operator<R>>) -> PrecClimber<R> {
+        let mut dense = ops.into_iter().zip(1..).fold(Vec::new(), |mut vec, (op, prec)| {
+            let mut next = Some(op);
+
+            while let Some(op) = next.take() {
+                match op {
+                    Operator { rule, assoc, next: op_next } => {
+                        vec.push((rule, prec, assoc));
+                        next = op_next.map(|op| *op);
+                    }
+                }
+            }
+
+            vec
+        });
+
+        dense.sort_by_key(|&(rule, _, _)| rule);
+
+        PrecClimber {
+            ops: Ops::Dense(dense.into_boxed_slice())
+        }
+    }
+
+    fn lookup(&self, rule: R) -> Option<(u32, Assoc)> {
+        match self.ops {
+            Ops::Hashed(ref map) => map.get(&rule).copied(),
+            Ops::Dense(ref slice) => {
+                slice.binary_search_by_key(&rule, |&(r, _, _)| r).ok().map(|i| {
+                    let (_, prec, assoc) = slice[i];
+                    (prec, assoc)
+                })
+            }
         }
     }
 
@@ -130,8 +261,9 @@ impl<R: RuleType> PrecClimber<R> {
     ///
     /// # Panics
     ///
-    /// Panics will occur when `pairs` is empty or when the alternating *primary*, *operator*,
-    /// *primary* order is not respected.
+    /// Panics will occur when `pairs` is empty, when the alternating *primary*, *operator*,
+    /// *primary* order is not respected, or when two `Assoc::Non` operators sharing a precedence
+    /// level are chained (e.g. `a < b < c`).
     ///
     /// # Examples
     ///
@@ -180,18 +312,25 @@ impl<R: RuleType> PrecClimber<R> {
         F: FnMut(Pair<R, I>) -> T,
         G: FnMut(T, Pair<R, I>, T) -> T
     {
+        let mut last_non_assoc_prec = None;
+
         while pairs.peek().is_some() {
             let rule = pairs.peek().unwrap().as_rule();
-            if let Some(&(prec, _)) = self.ops.get(&rule) {
+            if let Some((prec, assoc)) = self.lookup(rule) {
                 if prec >= min_prec {
+                    if assoc == Assoc::Non && last_non_assoc_prec == Some(prec) {
+                        panic!("non-associative operators cannot be chained at the same \
+                                precedence level");
+                    }
+
                     let op = pairs.next().unwrap();
                     let mut rhs = primary(pairs.next().expect("infix operator must be followed by \
                                                                a primary expression"));
 
                     while pairs.peek().is_some() {
                         let rule = pairs.peek().unwrap().as_rule();
-                        if let Some(&(new_prec, assoc)) = self.ops.get(&rule) {
-                            if new_prec > prec || assoc == Assoc::Right && new_prec == prec {
+                        if let Some((new_prec, new_assoc)) = self.lookup(rule) {
+                            if new_prec > prec || new_assoc == Assoc::Right && new_prec == prec {
                                 rhs = self.climb_rec(rhs, new_prec, pairs, primary, infix);
                             } else {
                                 break;
@@ -202,6 +341,97 @@ impl<R: RuleType> PrecClimber<R> {
                     }
 
                     lhs = infix(lhs, op, rhs);
+                    last_non_assoc_prec = if assoc == Assoc::Non { Some(prec) } else { None };
+                } else {
+                    break;
+                }
+            } else {
+                break;
+            }
+        }
+
+        lhs
+    }
+
+    /// Performs the precedence climbing algorithm on the `pairs` in a similar manner to
+    /// map-reduce, just like [`climb`](struct.PrecClimber.html#method.climb), but without
+    /// panicking. *Primary* pairs are mapped with `primary` and then reduced to one single result
+    /// with `infix`; both closures may fail, in which case their error is propagated immediately
+    /// without climbing any further. The conditions that make
+    /// [`climb`](struct.PrecClimber.html#method.climb) panic &mdash; an empty `Pairs`, a missing
+    /// trailing primary, or a chained `Assoc::Non` operator &mdash; are reported as a
+    /// [`ClimbError`](enum.ClimbError.html) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let primary = |pair| consume(pair, climber);
+    /// let infix = |lhs: i32, op: Pair<Rule, StringInput>, rhs: i32| {
+    ///     match op.rule() {
+    ///         Rule::plus => lhs.checked_add(rhs).ok_or("overflow"),
+    ///         _ => unreachable!()
+    ///     }
+    /// };
+    ///
+    /// let result = climber.try_climb(pairs, primary, infix)?;
+    /// ```
+    pub fn try_climb<I: Input, P, F, G, T, E>(
+        &self,
+        mut pairs: P,
+        mut primary: F,
+        mut infix: G
+    ) -> Result<T, ClimbError<E>>
+    where
+        P: Iterator<Item=Pair<R, I>>,
+        F: FnMut(Pair<R, I>) -> Result<T, E>,
+        G: FnMut(T, Pair<R, I>, T) -> Result<T, E>
+    {
+        let lhs = primary(pairs.next().ok_or(ClimbError::EmptyPairs)?).map_err(ClimbError::Fold)?;
+        self.try_climb_rec(lhs, 0, &mut pairs.peekable(), &mut primary, &mut infix)
+    }
+
+    fn try_climb_rec<I: Input, P, F, G, T, E>(
+        &self,
+        mut lhs: T,
+        min_prec: u32,
+        pairs: &mut Peekable<P>,
+        primary: &mut F,
+        infix: &mut G
+    ) -> Result<T, ClimbError<E>>
+    where
+        P: Iterator<Item=Pair<R, I>>,
+        F: FnMut(Pair<R, I>) -> Result<T, E>,
+        G: FnMut(T, Pair<R, I>, T) -> Result<T, E>
+    {
+        let mut last_non_assoc_prec = None;
+
+        while pairs.peek().is_some() {
+            let rule = pairs.peek().unwrap().as_rule();
+            if let Some((prec, assoc)) = self.lookup(rule) {
+                if prec >= min_prec {
+                    if assoc == Assoc::Non && last_non_assoc_prec == Some(prec) {
+                        return Err(ClimbError::NonAssociativeChain);
+                    }
+
+                    let op = pairs.next().unwrap();
+                    let mut rhs = primary(pairs.next().ok_or(ClimbError::MissingPrimary)?)
+                        .map_err(ClimbError::Fold)?;
+
+                    while pairs.peek().is_some() {
+                        let rule = pairs.peek().unwrap().as_rule();
+                        if let Some((new_prec, new_assoc)) = self.lookup(rule) {
+                            if new_prec > prec || new_assoc == Assoc::Right && new_prec == prec {
+                                rhs = self.try_climb_rec(rhs, new_prec, pairs, primary, infix)?;
+                            } else {
+                                break;
+                            }
+                        } else {
+                            break;
+                        }
+                    }
+
+                    lhs = infix(lhs, op, rhs).map_err(ClimbError::Fold)?;
+                    last_non_assoc_prec = if assoc == Assoc::Non { Some(prec) } else { None };
                 } else {
                     break;
                 }
@@ -210,6 +440,333 @@ impl<R: RuleType> PrecClimber<R> {
             }
         }
 
+        Ok(lhs)
+    }
+}
+
+/// An `enum` describing the ways [`PrecClimber::try_climb`](struct.PrecClimber.html#method.try_climb)
+/// can fail. `E` is the error type returned by the `primary` and `infix` closures.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClimbError<E> {
+    /// `try_climb` was called with an empty `Pairs`.
+    EmptyPairs,
+    /// An infix operator was not followed by a primary expression.
+    MissingPrimary,
+    /// Two `Assoc::Non` operators sharing a precedence level were chained (e.g. `a < b < c`).
+    NonAssociativeChain,
+    /// The `primary` or `infix` closure returned an error while folding the operator tree.
+    Fold(E)
+}
+
+/// A `struct` useful in order to perform [Pratt parsing][1] on expressions that mix prefix,
+/// postfix, and infix operators, such as unary `-`, postfix `?`, and binary `+`. Unlike
+/// [`PrecClimber`](struct.PrecClimber.html), operators registered here carry a fixity, and the
+/// token pairs contained in the `Pairs` are operands separated by infix operators, where every
+/// operand is any number of prefix operators, a *primary* pair, and any number of postfix
+/// operators.
+///
+/// [1]: https://matklad.github.io/2020/04/13/simple-but-powerful-pratt-parsing.html
+#[derive(Debug)]
+pub struct PrattParser<R: RuleType> {
+    prefix: HashMap<R, u32>,
+    postfix: HashMap<R, u32>,
+    infix: HashMap<R, (u32, u32)>
+}
+
+impl<R: RuleType> PrattParser<R> {
+    /// Creates a new `PrattParser` from the `PrattOp`s contained in `ops`. Every entry in the
+    /// `Vec` has precedence *index + 1*. In order to have operators with same precedence, they
+    /// need to be chained with `|` between them. Each precedence level is turned into a left and
+    /// right binding power: an infix left-associative operator at level `p` gets
+    /// `(left=2p, right=2p+1)`, a right-associative one gets `(2p+1, 2p)`; a prefix operator only
+    /// gets a right binding power of `2p`, a postfix operator only a left binding power of `2p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an infix `PrattOp` is registered with `Assoc::Non`. Binding powers have no way to
+    /// express "reject a second same-precedence operator", which is what `Assoc::Non` means for
+    /// [`PrecClimber`](struct.PrecClimber.html); use
+    /// [`PrecClimber::try_climb`](struct.PrecClimber.html#method.try_climb) for non-associative
+    /// infix operators instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pest::prec_climber::{Assoc, PrattOp, PrattParser};
+    /// # #[allow(non_camel_case_types)]
+    /// # #[allow(dead_code)]
+    /// # #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    /// # enum Rule {
+    /// #     neg,
+    /// #     fac,
+    /// #     plus,
+    /// #     times
+    /// # }
+    /// let pratt = PrattParser::new(vec![
+    ///     PrattOp::infix(Rule::plus, Assoc::Left),
+    ///     PrattOp::infix(Rule::times, Assoc::Left),
+    ///     PrattOp::prefix(Rule::neg) | PrattOp::postfix(Rule::fac)
+    /// ]);
+    /// ```
+    pub fn new(ops: Vec<PrattOp<R>>) -> PrattParser<R> {
+        let mut prefix = HashMap::new();
+        let mut postfix = HashMap::new();
+        let mut infix = HashMap::new();
+
+        for (op, prec) in ops.into_iter().zip(1u32..) {
+            let mut next = Some(op);
+
+            while let Some(op) = next.take() {
+                match op {
+                    PrattOp { rule, fixity, next: op_next } => {
+                        match fixity {
+                            Fixity::Prefix => {
+                                prefix.insert(rule, 2 * prec);
+                            }
+                            Fixity::Postfix => {
+                                postfix.insert(rule, 2 * prec);
+                            }
+                            Fixity::Infix(Assoc::Left) => {
+                                infix.insert(rule, (2 * prec, 2 * prec + 1));
+                            }
+                            Fixity::Infix(Assoc::Right) => {
+                                infix.insert(rule, (2 * prec + 1, 2 * prec));
+                            }
+                            Fixity::Infix(Assoc::Non) => {
+                                panic!("Assoc::Non is not supported by PrattParser; use \
+                                        PrecClimber::try_climb for non-associative infix \
+                                        operators");
+                            }
+                        }
+
+                        next = op_next.map(|op| *op);
+                    }
+                }
+            }
+        }
+
+        PrattParser {
+            prefix,
+            postfix,
+            infix
+        }
+    }
+
+    /// Performs the Pratt parsing algorithm on the `pairs` in a similar manner to map-reduce.
+    /// *Primary* pairs are mapped with `primary`, the prefix operators in front of each operand
+    /// are folded with `prefix`, the postfix operators after each operand are folded with
+    /// `postfix`, and the infix operators between operands are folded with `infix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics will occur when `pairs` is empty or when the expected
+    /// prefix* primary (postfix | infix prefix* primary)* order is not respected.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let primary = |pair| consume(pair, pratt);
+    /// let prefix = |op: Pair<Rule, StringInput>, rhs: i32| {
+    ///     match op.rule() {
+    ///         Rule::neg => -rhs,
+    ///         _ => unreachable!()
+    ///     }
+    /// };
+    /// let postfix = |lhs: i32, op: Pair<Rule, StringInput>| {
+    ///     match op.rule() {
+    ///         Rule::fac => (1..=lhs).product(),
+    ///         _ => unreachable!()
+    ///     }
+    /// };
+    /// let infix = |lhs: i32, op: Pair<Rule, StringInput>, rhs: i32| {
+    ///     match op.rule() {
+    ///         Rule::plus => lhs + rhs,
+    ///         Rule::times => lhs * rhs,
+    ///         _ => unreachable!()
+    ///     }
+    /// };
+    ///
+    /// let result = pratt.parse(pairs, primary, prefix, postfix, infix);
+    /// ```
+    pub fn parse<I: Input, P, FPrimary, FPrefix, FPostfix, FInfix, T>(
+        &self,
+        mut pairs: P,
+        mut primary: FPrimary,
+        mut prefix: FPrefix,
+        mut postfix: FPostfix,
+        mut infix: FInfix
+    ) -> T
+    where
+        P: Iterator<Item=Pair<R, I>>,
+        FPrimary: FnMut(Pair<R, I>) -> T,
+        FPrefix: FnMut(Pair<R, I>, T) -> T,
+        FPostfix: FnMut(T, Pair<R, I>) -> T,
+        FInfix: FnMut(T, Pair<R, I>, T) -> T
+    {
+        self.parse_expr(0, &mut pairs.peekable(), &mut primary, &mut prefix, &mut postfix, &mut infix)
+    }
+
+    fn parse_expr<I: Input, P, FPrimary, FPrefix, FPostfix, FInfix, T>(
+        &self,
+        min_bp: u32,
+        pairs: &mut Peekable<P>,
+        primary: &mut FPrimary,
+        prefix: &mut FPrefix,
+        postfix: &mut FPostfix,
+        infix: &mut FInfix
+    ) -> T
+    where
+        P: Iterator<Item=Pair<R, I>>,
+        FPrimary: FnMut(Pair<R, I>) -> T,
+        FPrefix: FnMut(Pair<R, I>, T) -> T,
+        FPostfix: FnMut(T, Pair<R, I>) -> T,
+        FInfix: FnMut(T, Pair<R, I>, T) -> T
+    {
+        let rule = pairs.peek().expect("Pratt parsing requires a non-empty Pairs").as_rule();
+
+        let mut lhs = if let Some(&right_bp) = self.prefix.get(&rule) {
+            let op = pairs.next().unwrap();
+            let rhs = self.parse_expr(right_bp, pairs, primary, prefix, postfix, infix);
+            prefix(op, rhs)
+        } else {
+            primary(pairs.next().unwrap())
+        };
+
+        while let Some(pair) = pairs.peek() {
+            let rule = pair.as_rule();
+
+            if let Some(&left_bp) = self.postfix.get(&rule) {
+                if left_bp < min_bp {
+                    break;
+                }
+
+                let op = pairs.next().unwrap();
+                lhs = postfix(lhs, op);
+            } else if let Some(&(left_bp, right_bp)) = self.infix.get(&rule) {
+                if left_bp < min_bp {
+                    break;
+                }
+
+                let op = pairs.next().unwrap();
+                let rhs = self.parse_expr(right_bp, pairs, primary, prefix, postfix, infix);
+                lhs = infix(lhs, op, rhs);
+            } else {
+                break;
+            }
+        }
+
         lhs
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use inputs::StringInput;
+    use iterators::Pairs;
+    use {state, ParserState};
+
+    #[allow(non_camel_case_types)]
+    #[allow(dead_code)]
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    enum Rule {
+        int,
+        plus,
+        times,
+        neg,
+        fac,
+        lt
+    }
+
+    fn parse(input: &str, rules: &[Rule]) -> Pairs<Rule, StringInput> {
+        let tokens: Vec<_> = rules.iter().cloned().zip(input.chars()).collect();
+        let rc_input = Rc::new(StringInput::new(input.to_owned()));
+
+        state(rc_input, move |mut state: Box<ParserState<Rule, StringInput>>| {
+            for (rule, ch) in tokens {
+                state = state.rule(rule, |state| state.match_string(&ch.to_string()))?;
+            }
+
+            Ok(state)
+        }).unwrap()
+    }
+
+    #[test]
+    fn pratt_prefix_postfix_infix() {
+        let pairs = parse(
+            "-1!+2*3",
+            &[Rule::neg, Rule::int, Rule::fac, Rule::plus, Rule::int, Rule::times, Rule::int]
+        );
+
+        let pratt = PrattParser::new(vec![
+            PrattOp::infix(Rule::plus, Assoc::Left),
+            PrattOp::infix(Rule::times, Assoc::Left),
+            PrattOp::prefix(Rule::neg) | PrattOp::postfix(Rule::fac)
+        ]);
+
+        let result = pratt.parse(
+            pairs,
+            |pair| pair.as_str().parse::<i32>().unwrap(),
+            |_, rhs: i32| -rhs,
+            |lhs: i32, _| (1..=lhs).product(),
+            |lhs: i32, op, rhs: i32| match op.as_rule() {
+                Rule::plus => lhs + rhs,
+                Rule::times => lhs * rhs,
+                _ => unreachable!()
+            }
+        );
+
+        // -(1!) + 2 * 3 == 5
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-associative operators cannot be chained at the same \
+                               precedence level")]
+    fn non_associative_chain_panics() {
+        let pairs = parse("1<2<3", &[Rule::int, Rule::lt, Rule::int, Rule::lt, Rule::int]);
+        let climber = PrecClimber::new(vec![Operator::new(Rule::lt, Assoc::Non)]);
+
+        climber.climb(
+            pairs,
+            |pair| pair.as_str().parse::<i32>().unwrap(),
+            |lhs, _, rhs| if lhs < rhs { 1 } else { 0 }
+        );
+    }
+
+    #[test]
+    fn non_associative_chain_try_climb_errors() {
+        let pairs = parse("1<2<3", &[Rule::int, Rule::lt, Rule::int, Rule::lt, Rule::int]);
+        let climber = PrecClimber::new(vec![Operator::new(Rule::lt, Assoc::Non)]);
+
+        let result = climber.try_climb(
+            pairs,
+            |pair| Ok::<i32, ()>(pair.as_str().parse().unwrap()),
+            |lhs, _, rhs| Ok(if lhs < rhs { 1 } else { 0 })
+        );
+
+        assert_eq!(result, Err(ClimbError::NonAssociativeChain));
+    }
+
+    #[test]
+    fn try_climb_propagates_closure_error() {
+        let pairs = parse("1+0", &[Rule::int, Rule::plus, Rule::int]);
+        let climber = PrecClimber::new(vec![Operator::new(Rule::plus, Assoc::Left)]);
+
+        let result = climber.try_climb(
+            pairs,
+            |pair| Ok::<i32, &'static str>(pair.as_str().parse().unwrap()),
+            |lhs: i32, _, rhs: i32| {
+                if rhs == 0 {
+                    Err("division by zero")
+                } else {
+                    Ok(lhs / rhs)
+                }
+            }
+        );
+
+        assert_eq!(result, Err(ClimbError::Fold("division by zero")));
+    }
+}